@@ -60,31 +60,155 @@ fn isqrt(n: &BigInt) -> BigInt {
     }
 }
 
-/// Compute `num_digits` decimal digits of Pi (after the decimal point)
-fn compute_pi_digits(num_digits: usize) -> Vec<u8> {
+/// Chudnovsky needs roughly 14.18 decimal digits per series term; returns
+/// `(num_terms, total)` where `total` is `num_digits` plus a safety margin of
+/// guard digits dropped before display.
+fn terms_for_digits(num_digits: usize) -> (u64, usize) {
     let extra = 20;
     let total = num_digits + extra;
     let num_terms = (total as f64 / 14.181647) as u64 + 2;
+    (num_terms, total)
+}
 
-    let (_, q, t) = binary_split(0, num_terms);
+/// Wall-clock breakdown of a single digit computation, used by `--bench`
+/// to compare strategies stage-by-stage instead of just eyeballing d/s.
+#[derive(Default, Clone, Copy)]
+struct PiTimings {
+    split: Duration,
+    isqrt: Duration,
+    division: Duration,
+}
 
+/// Turn a binary-splitting `(P, Q, T)` triple into decimal digits of Pi,
+/// reporting time spent in `isqrt` separately from the final division and
+/// decimal-string extraction.
+fn digits_from_triple_timed(
+    q: &BigInt,
+    t: &BigInt,
+    num_digits: usize,
+    total: usize,
+) -> (Vec<u8>, Duration, Duration) {
     // π × 10^total = Q × 426880 × √(10005 × 10^(2·total)) / T
+    let isqrt_start = Instant::now();
     let ten_pow = BigInt::from(10u32).pow(2 * total as u32);
     let sqrt_c = isqrt(&(BigInt::from(10005u32) * ten_pow));
-    let pi_scaled = q * 426880u32 * sqrt_c / t;
+    let isqrt_time = isqrt_start.elapsed();
 
+    let division_start = Instant::now();
+    let pi_scaled = q * 426880u32 * sqrt_c / t;
     let s = pi_scaled.to_string();
-    s.bytes()
+    let digits = s
+        .bytes()
         .skip(1) // skip leading '3'
         .take(num_digits)
         .map(|b| b - b'0')
-        .collect()
+        .collect();
+    let division_time = division_start.elapsed();
+
+    (digits, isqrt_time, division_time)
+}
+
+/// Compute `num_digits` decimal digits of Pi (after the decimal point) from
+/// scratch, recomputing the full binary-splitting tree every call, and
+/// report a `--bench`-style timing breakdown of the binary splitting /
+/// isqrt / final division stages.
+fn compute_pi_digits_timed(num_digits: usize) -> (Vec<u8>, PiTimings) {
+    let (num_terms, total) = terms_for_digits(num_digits);
+
+    let split_start = Instant::now();
+    let (_, q, t) = binary_split(0, num_terms);
+    let split_time = split_start.elapsed();
+
+    let (digits, isqrt_time, division_time) =
+        digits_from_triple_timed(&q, &t, num_digits, total);
+    (
+        digits,
+        PiTimings {
+            split: split_time,
+            isqrt: isqrt_time,
+            division: division_time,
+        },
+    )
+}
+
+/// Stateful Chudnovsky binary-splitting engine that caches the `(P, Q, T)`
+/// triple for the prefix of terms already computed, so a growing series of
+/// `extend_to` calls only does binary splitting on the newly needed suffix
+/// `[num_terms_old, num_terms_new)` instead of recomputing that recursive
+/// sub-tree from scratch. Note the saving is partial: merging the cached
+/// prefix back in (`self.q * qr`, `self.p * pr`) is itself a full-size
+/// big-integer multiplication comparable to a fresh split at that size, so
+/// the end-to-end win over `compute_pi_digits_timed` on the doubling access
+/// pattern `run()` uses is modest (see `--bench`), not a removal of the
+/// dominant cost.
+struct PiEngine {
+    p: BigInt,
+    q: BigInt,
+    t: BigInt,
+    num_terms: u64,
+}
+
+impl PiEngine {
+    fn new() -> Self {
+        Self {
+            p: BigInt::one(),
+            q: BigInt::one(),
+            t: BigInt::zero(),
+            num_terms: 0,
+        }
+    }
+
+    /// Grow the cached prefix (if needed) to cover `num_digits`, merge it
+    /// with the freshly split suffix, and return the decimal digits.
+    fn extend_to(&mut self, num_digits: usize) -> Vec<u8> {
+        self.extend_to_timed(num_digits).0
+    }
+
+    /// Same as `extend_to`, but also reports a `--bench`-style timing
+    /// breakdown. `split` is zero when the cached prefix already covers
+    /// `num_digits` and no new binary splitting was needed.
+    fn extend_to_timed(&mut self, num_digits: usize) -> (Vec<u8>, PiTimings) {
+        let (num_terms, total) = terms_for_digits(num_digits);
+        let mut split_time = Duration::default();
+        if num_terms > self.num_terms {
+            let split_start = Instant::now();
+            if self.num_terms == 0 {
+                let (p, q, t) = binary_split(0, num_terms);
+                self.p = p;
+                self.q = q;
+                self.t = t;
+            } else {
+                let (pr, qr, tr) = binary_split(self.num_terms, num_terms);
+                let new_t = &qr * &self.t + &self.p * &tr;
+                let new_p = &self.p * &pr;
+                let new_q = &self.q * &qr;
+                self.p = new_p;
+                self.q = new_q;
+                self.t = new_t;
+            }
+            self.num_terms = num_terms;
+            split_time = split_start.elapsed();
+        }
+        let (digits, isqrt_time, division_time) =
+            digits_from_triple_timed(&self.q, &self.t, num_digits, total);
+        (
+            digits,
+            PiTimings {
+                split: split_time,
+                isqrt: isqrt_time,
+                division: division_time,
+            },
+        )
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════
 //  Statistics Tracker
 // ═══════════════════════════════════════════════════════════════
 
+/// Size of the sliding window used for the local-normality panel.
+const WINDOW_SIZE: usize = 10_000;
+
 struct Stats {
     counts: [u64; 10],
     total: u64,
@@ -93,6 +217,20 @@ struct Stats {
     max_dev_history: Vec<f64>,
     entropy_history: Vec<f64>,
     chi_sq_history: Vec<f64>,
+    // Last WINDOW_SIZE digits, maintained incrementally so local (as opposed to
+    // cumulative) normality can be read off in O(1) per digit.
+    window_digits: VecDeque<u8>,
+    window_counts: [u64; 10],
+    window_chi_sq_history: Vec<f64>,
+    // Overlapping k-gram (block) frequencies for k=2 and k=3, maintained via a
+    // rolling base-10 window so true (not just single-digit) normality can be
+    // tested: `window = (window * 10 + d) % 10^k`.
+    k2_window: u32,
+    k2_counts: [u64; 100],
+    k2_total: u64,
+    k3_window: u32,
+    k3_counts: [u64; 1000],
+    k3_total: u64,
     start: Instant,
 }
 
@@ -106,6 +244,15 @@ impl Stats {
             max_dev_history: Vec::new(),
             entropy_history: Vec::new(),
             chi_sq_history: Vec::new(),
+            window_digits: VecDeque::with_capacity(WINDOW_SIZE),
+            window_counts: [0; 10],
+            window_chi_sq_history: Vec::new(),
+            k2_window: 0,
+            k2_counts: [0; 100],
+            k2_total: 0,
+            k3_window: 0,
+            k3_counts: [0; 1000],
+            k3_total: 0,
             start: Instant::now(),
         }
     }
@@ -122,6 +269,28 @@ impl Stats {
             self.recent_digits.drain(..200);
         }
 
+        // Slide the local-normality window: add the new digit, and if the
+        // window has grown past WINDOW_SIZE, drop the oldest one.
+        self.window_counts[d as usize] += 1;
+        self.window_digits.push_back(d);
+        if self.window_digits.len() > WINDOW_SIZE {
+            if let Some(old) = self.window_digits.pop_front() {
+                self.window_counts[old as usize] -= 1;
+            }
+        }
+
+        // Roll the k-gram windows forward and tally the completed blocks.
+        self.k2_window = (self.k2_window * 10 + d as u32) % 100;
+        if self.total >= 2 {
+            self.k2_counts[self.k2_window as usize] += 1;
+            self.k2_total += 1;
+        }
+        self.k3_window = (self.k3_window * 10 + d as u32) % 1000;
+        if self.total >= 3 {
+            self.k3_counts[self.k3_window as usize] += 1;
+            self.k3_total += 1;
+        }
+
         // Sample convergence at adaptive intervals
         let interval = match self.total {
             0..=999 => 50,
@@ -133,11 +302,14 @@ impl Stats {
             self.max_dev_history.push(self.max_deviation());
             self.entropy_history.push(self.entropy());
             self.chi_sq_history.push(self.chi_squared());
+            self.window_chi_sq_history.push(self.windowed_chi_squared());
             // Decimate if too long
             if self.max_dev_history.len() > 300 {
                 self.max_dev_history = self.max_dev_history.iter().step_by(2).copied().collect();
                 self.entropy_history = self.entropy_history.iter().step_by(2).copied().collect();
                 self.chi_sq_history = self.chi_sq_history.iter().step_by(2).copied().collect();
+                self.window_chi_sq_history =
+                    self.window_chi_sq_history.iter().step_by(2).copied().collect();
             }
         }
     }
@@ -183,6 +355,67 @@ impl Stats {
             .fold(0.0f64, f64::max)
     }
 
+    /// Chi-squared of the last (up to) WINDOW_SIZE digits only, so local
+    /// clustering shows up even when the cumulative statistics look uniform.
+    fn windowed_chi_squared(&self) -> f64 {
+        let n = self.window_digits.len() as f64;
+        if n == 0.0 {
+            return 0.0;
+        }
+        let exp = n / 10.0;
+        self.window_counts
+            .iter()
+            .map(|&c| {
+                let d = c as f64 - exp;
+                d * d / exp
+            })
+            .sum()
+    }
+
+    fn windowed_max_deviation(&self) -> f64 {
+        let n = self.window_digits.len() as f64;
+        if n == 0.0 {
+            return 0.0;
+        }
+        self.window_counts
+            .iter()
+            .map(|&c| (c as f64 / n * 100.0 - 10.0).abs())
+            .fold(0.0f64, f64::max)
+    }
+
+    fn chi_squared_k2(&self) -> f64 {
+        chi_squared_blocks(&self.k2_counts, self.k2_total)
+    }
+
+    fn chi_squared_k3(&self) -> f64 {
+        chi_squared_blocks(&self.k3_counts, self.k3_total)
+    }
+
+    /// Returns the "<most over-represented>, <most under-represented>"
+    /// k-digit blocks, zero-padded to width `k`.
+    fn extreme_blocks(counts: &[u64], total: u64, k: usize) -> (String, String) {
+        if total == 0 {
+            return ("--".into(), "--".into());
+        }
+        let exp = total as f64 / counts.len() as f64;
+        let mut over_idx = 0;
+        let mut under_idx = 0;
+        let mut over_dev = f64::MIN;
+        let mut under_dev = f64::MAX;
+        for (i, &c) in counts.iter().enumerate() {
+            let dev = c as f64 - exp;
+            if dev > over_dev {
+                over_dev = dev;
+                over_idx = i;
+            }
+            if dev < under_dev {
+                under_dev = dev;
+                under_idx = i;
+            }
+        }
+        (format!("{:0width$}", over_idx, width = k), format!("{:0width$}", under_idx, width = k))
+    }
+
     fn speed(&self) -> f64 {
         let elapsed = self.start.elapsed().as_secs_f64();
         if elapsed > 0.0 {
@@ -191,6 +424,105 @@ impl Stats {
             0.0
         }
     }
+
+    /// Serialize the final digit counts, summary statistics, and the full
+    /// convergence time series to JSON, so the curves can be fed into
+    /// external plotting/stats tools instead of staying trapped in the TUI.
+    fn export_json(&self, elapsed: Duration) -> String {
+        format!(
+            "{{\n\
+             \x20\x20\"total_digits\": {},\n\
+             \x20\x20\"elapsed_secs\": {:.3},\n\
+             \x20\x20\"chi_squared\": {:.6},\n\
+             \x20\x20\"entropy\": {:.6},\n\
+             \x20\x20\"max_deviation\": {:.6},\n\
+             \x20\x20\"digit_counts\": [{}],\n\
+             \x20\x20\"max_dev_history\": [{}],\n\
+             \x20\x20\"entropy_history\": [{}],\n\
+             \x20\x20\"chi_sq_history\": [{}]\n\
+             }}\n",
+            self.total,
+            elapsed.as_secs_f64(),
+            self.chi_squared(),
+            self.entropy(),
+            self.max_deviation(),
+            join_csv(self.counts.iter().map(|c| c.to_string())),
+            join_f64_csv(&self.max_dev_history),
+            join_f64_csv(&self.entropy_history),
+            join_f64_csv(&self.chi_sq_history),
+        )
+    }
+
+    /// Same data as `export_json`, but as a CSV of the convergence time
+    /// series (one sample per row) with the scalar summary stats as leading
+    /// `#`-prefixed comment lines.
+    fn export_csv(&self, elapsed: Duration) -> String {
+        let mut out = format!(
+            "# total_digits,{}\n# elapsed_secs,{:.3}\n# chi_squared,{:.6}\n# entropy,{:.6}\n# max_deviation,{:.6}\n",
+            self.total,
+            elapsed.as_secs_f64(),
+            self.chi_squared(),
+            self.entropy(),
+            self.max_deviation(),
+        );
+        out.push_str("sample,max_deviation,entropy,chi_squared\n");
+        let n = self
+            .max_dev_history
+            .len()
+            .min(self.entropy_history.len())
+            .min(self.chi_sq_history.len());
+        for i in 0..n {
+            out.push_str(&format!(
+                "{},{:.6},{:.6},{:.6}\n",
+                i, self.max_dev_history[i], self.entropy_history[i], self.chi_sq_history[i]
+            ));
+        }
+        out
+    }
+}
+
+fn join_f64_csv(values: &[f64]) -> String {
+    join_csv(values.iter().map(|v| format!("{:.6}", v)))
+}
+
+fn join_csv(items: impl Iterator<Item = String>) -> String {
+    items.collect::<Vec<_>>().join(", ")
+}
+
+/// Write `stats` to `path` as JSON, or as CSV if `path` ends in `.csv`.
+fn export_stats(path: &str, stats: &Stats, elapsed: Duration) -> io::Result<()> {
+    let content = if path.to_lowercase().ends_with(".csv") {
+        stats.export_csv(elapsed)
+    } else {
+        stats.export_json(elapsed)
+    };
+    std::fs::write(path, content)
+}
+
+/// Chi-squared for a block-frequency table: `counts` holds one entry per
+/// possible k-digit block, `total` is the number of blocks tallied.
+fn chi_squared_blocks(counts: &[u64], total: u64) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+    let exp = total as f64 / counts.len() as f64;
+    counts
+        .iter()
+        .map(|&c| {
+            let d = c as f64 - exp;
+            d * d / exp
+        })
+        .sum()
+}
+
+/// Approximate the 95th-percentile chi-squared critical value for `df`
+/// degrees of freedom via the Wilson-Hilferty cube-root approximation, so
+/// the UNIFORM/SKEWED threshold can be recomputed for any block size k
+/// (df = 10^k - 1) instead of only the single hard-coded digit-level table.
+fn chi_sq_critical_95(df: f64) -> f64 {
+    const Z_95: f64 = 1.6449;
+    let term = 1.0 - 2.0 / (9.0 * df) + Z_95 * (2.0 / (9.0 * df)).sqrt();
+    df * term.powi(3)
 }
 
 // ═══════════════════════════════════════════════════════════════
@@ -246,13 +578,28 @@ const BAR_COLORS: [style::Color; 10] = [
     style::Color::DarkYellow,
 ];
 
-fn draw(stdout: &mut io::Stdout, stats: &Stats, first: &mut bool) -> io::Result<()> {
-    let (tw, _) = terminal::size().unwrap_or((80, 24));
+/// Tracks draw()'s one-time full-screen clear plus the last-seen terminal
+/// size, so a mid-session resize (which changes how many optional rows fit)
+/// re-clears instead of leaving stale rows from a taller/shorter layout.
+struct DrawState {
+    first: bool,
+    last_dims: (u16, u16),
+}
+
+impl DrawState {
+    fn new() -> Self {
+        Self { first: true, last_dims: (0, 0) }
+    }
+}
+
+fn draw(stdout: &mut io::Stdout, stats: &Stats, state: &mut DrawState) -> io::Result<()> {
+    let (tw, th) = terminal::size().unwrap_or((80, 24));
     let w = tw as usize;
 
-    if *first {
+    if state.first || state.last_dims != (tw, th) {
         execute!(stdout, terminal::Clear(ClearType::All))?;
-        *first = false;
+        state.first = false;
+        state.last_dims = (tw, th);
     }
 
     let sep: String = "\u{2500}".repeat(w);
@@ -385,41 +732,139 @@ fn draw(stdout: &mut io::Stdout, stats: &Stats, first: &mut bool) -> io::Result<
         )?;
     }
 
-    // Row 18: Convergence sparkline — max deviation
+    // Rows 17+: everything below here is optional and competes for
+    // whatever vertical space the terminal actually has (`th`), in priority
+    // order — the original convergence sparklines first, then the newer
+    // local-normality / block-frequency panels — so a short terminal drops
+    // the newest rows instead of overflowing past the controls line.
     let spark_w = w.saturating_sub(38).max(10);
     let spark_dev = sparkline(&stats.max_dev_history, spark_w);
-    execute!(
-        stdout,
-        cursor::MoveTo(0, 18),
-        style::PrintStyledContent("  Max |deviation| \u{2192} 0 : ".dark_grey()),
-        style::PrintStyledContent(spark_dev.with(style::Color::Cyan)),
-        terminal::Clear(ClearType::UntilNewLine),
-    )?;
-
-    // Row 19: Convergence sparkline — entropy
     let spark_ent = sparkline(&stats.entropy_history, spark_w);
-    execute!(
-        stdout,
-        cursor::MoveTo(0, 19),
-        style::PrintStyledContent("  Entropy \u{2192} 3.3219 : ".dark_grey()),
-        style::PrintStyledContent(spark_ent.with(style::Color::Green)),
-        terminal::Clear(ClearType::UntilNewLine),
-    )?;
-
-    // Row 20: Convergence sparkline — chi-squared
     let spark_chi = sparkline(&stats.chi_sq_history, spark_w);
-    execute!(
-        stdout,
-        cursor::MoveTo(0, 20),
-        style::PrintStyledContent("  \u{03C7}\u{00B2} \u{2192} 0          : ".dark_grey()),
-        style::PrintStyledContent(spark_chi.with(style::Color::Yellow)),
-        terminal::Clear(ClearType::UntilNewLine),
-    )?;
+    let spark_w_chi = sparkline(&stats.window_chi_sq_history, spark_w);
+
+    type OptionalRow<'a> = Box<dyn FnOnce(&mut io::Stdout, u16) -> io::Result<()> + 'a>;
+    let mut optional: Vec<OptionalRow> = Vec::new();
+
+    optional.push(Box::new(move |stdout, row| {
+        execute!(
+            stdout,
+            cursor::MoveTo(0, row),
+            style::PrintStyledContent("  Max |deviation| \u{2192} 0 : ".dark_grey()),
+            style::PrintStyledContent(spark_dev.with(style::Color::Cyan)),
+            terminal::Clear(ClearType::UntilNewLine),
+        )
+    }));
+    optional.push(Box::new(move |stdout, row| {
+        execute!(
+            stdout,
+            cursor::MoveTo(0, row),
+            style::PrintStyledContent("  Entropy \u{2192} 3.3219 : ".dark_grey()),
+            style::PrintStyledContent(spark_ent.with(style::Color::Green)),
+            terminal::Clear(ClearType::UntilNewLine),
+        )
+    }));
+    optional.push(Box::new(move |stdout, row| {
+        execute!(
+            stdout,
+            cursor::MoveTo(0, row),
+            style::PrintStyledContent("  \u{03C7}\u{00B2} \u{2192} 0          : ".dark_grey()),
+            style::PrintStyledContent(spark_chi.with(style::Color::Yellow)),
+            terminal::Clear(ClearType::UntilNewLine),
+        )
+    }));
+
+    if !stats.window_digits.is_empty() {
+        let w_chi = stats.windowed_chi_squared();
+        let w_dev = stats.windowed_max_deviation();
+        let w_len = stats.window_digits.len() as u64;
+        let w_label = if w_chi < 16.919 {
+            "UNIFORM".with(style::Color::Green)
+        } else {
+            "SKEWED".with(style::Color::Yellow)
+        };
+        optional.push(Box::new(move |stdout, row| {
+            execute!(
+                stdout,
+                cursor::MoveTo(0, row),
+                style::PrintStyledContent("  Windowed (last ".dark_grey()),
+                style::Print(fmt_num(w_len)),
+                style::PrintStyledContent(") \u{03C7}\u{00B2}= ".dark_grey()),
+                style::Print(format!("{:<8.3} ", w_chi)),
+                style::PrintStyledContent(w_label),
+                style::Print(format!("   |dev|max: {:.3}%", w_dev)),
+                terminal::Clear(ClearType::UntilNewLine),
+            )
+        }));
+    }
+
+    if stats.k2_total > 0 {
+        let k2_chi = stats.chi_squared_k2();
+        let k2_label = if k2_chi < chi_sq_critical_95(99.0) {
+            "UNIFORM".with(style::Color::Green)
+        } else {
+            "SKEWED".with(style::Color::Yellow)
+        };
+        let (over, under) = Stats::extreme_blocks(&stats.k2_counts, stats.k2_total, 2);
+        optional.push(Box::new(move |stdout, row| {
+            execute!(
+                stdout,
+                cursor::MoveTo(0, row),
+                style::PrintStyledContent("  2-gram \u{03C7}\u{00B2}= ".bold()),
+                style::Print(format!("{:<8.3} ", k2_chi)),
+                style::PrintStyledContent(k2_label),
+                style::Print(format!("   most: '{}'  least: '{}'", over, under)),
+                terminal::Clear(ClearType::UntilNewLine),
+            )
+        }));
+    }
+
+    if stats.k3_total > 0 {
+        let k3_chi = stats.chi_squared_k3();
+        let k3_label = if k3_chi < chi_sq_critical_95(999.0) {
+            "UNIFORM".with(style::Color::Green)
+        } else {
+            "SKEWED".with(style::Color::Yellow)
+        };
+        let (over, under) = Stats::extreme_blocks(&stats.k3_counts, stats.k3_total, 3);
+        optional.push(Box::new(move |stdout, row| {
+            execute!(
+                stdout,
+                cursor::MoveTo(0, row),
+                style::PrintStyledContent("  3-gram \u{03C7}\u{00B2}= ".bold()),
+                style::Print(format!("{:<8.3} ", k3_chi)),
+                style::PrintStyledContent(k3_label),
+                style::Print(format!("   most: '{}'  least: '{}'", over, under)),
+                terminal::Clear(ClearType::UntilNewLine),
+            )
+        }));
+    }
+
+    optional.push(Box::new(move |stdout, row| {
+        execute!(
+            stdout,
+            cursor::MoveTo(0, row),
+            style::PrintStyledContent("  Windowed \u{03C7}\u{00B2} \u{2192} 0 : ".dark_grey()),
+            style::PrintStyledContent(spark_w_chi.with(style::Color::Magenta)),
+            terminal::Clear(ClearType::UntilNewLine),
+        )
+    }));
+
+    // Reserve the bottom row for controls; draw optional rows top-down,
+    // in priority order, only as far as they fit above it.
+    let last_row = th.saturating_sub(1).max(17);
+    let mut row = 17u16;
+    for render in optional {
+        if row >= last_row {
+            break;
+        }
+        render(stdout, row)?;
+        row += 1;
+    }
 
-    // Row 22: Controls
     execute!(
         stdout,
-        cursor::MoveTo(0, 22),
+        cursor::MoveTo(0, row),
         style::PrintStyledContent("  Press Ctrl+C or ESC to stop".dark_grey()),
         terminal::Clear(ClearType::UntilNewLine),
     )?;
@@ -427,6 +872,71 @@ fn draw(stdout: &mut io::Stdout, stats: &Stats, first: &mut bool) -> io::Result<
     stdout.flush()
 }
 
+// ═══════════════════════════════════════════════════════════════
+//  Benchmark mode
+// ═══════════════════════════════════════════════════════════════
+
+/// Target digit counts used by `--bench`, mirroring the doubling growth
+/// pattern the live feed in `run()` actually drives the engine through
+/// (1,000 → 128,000), so the comparison reflects real usage instead of an
+/// arbitrary spread of target sizes.
+const BENCH_TARGETS: [usize; 8] = [1_000, 2_000, 4_000, 8_000, 16_000, 32_000, 64_000, 128_000];
+
+/// Run `compute` for each of `BENCH_TARGETS` under `budget`, printing a
+/// timing-breakdown row per target. Once a target exceeds the budget, every
+/// larger target in the list is reported N/A without being attempted.
+fn bench_strategy(label: &str, budget: Duration, mut compute: impl FnMut(usize) -> (Vec<u8>, PiTimings)) {
+    println!("-- {} --", label);
+    let mut exceeded = false;
+    for &target in &BENCH_TARGETS {
+        if exceeded {
+            println!("{:>10}   N/A (skipped: smaller target already exceeded budget)", fmt_num(target as u64));
+            continue;
+        }
+        let start = Instant::now();
+        let (_digits, timings) = compute(target);
+        let total = start.elapsed();
+        if total > budget {
+            println!(
+                "{:>10}   N/A (exceeded {:.0}s budget, took {:.1}s)",
+                fmt_num(target as u64),
+                budget.as_secs_f64(),
+                total.as_secs_f64()
+            );
+            exceeded = true;
+            continue;
+        }
+        println!(
+            "{:>10} {:>12.1} {:>12.1} {:>12.1} {:>12.1} {:>14.0}",
+            fmt_num(target as u64),
+            timings.split.as_secs_f64() * 1000.0,
+            timings.isqrt.as_secs_f64() * 1000.0,
+            timings.division.as_secs_f64() * 1000.0,
+            total.as_secs_f64() * 1000.0,
+            target as f64 / total.as_secs_f64(),
+        );
+    }
+}
+
+/// Non-interactive `--bench` subcommand: compares the full-recompute
+/// `compute_pi_digits_timed` strategy against the resumable `PiEngine`
+/// strategy (see its doc comment for why the win is modest), over the same
+/// doubling target sequence `run()` drives the live feed through, under a
+/// fixed wall-clock budget per target.
+fn run_bench() {
+    let budget = Duration::from_secs(10);
+    println!("Pi digit computation benchmark (budget: {:.0}s per target)\n", budget.as_secs_f64());
+    println!(
+        "{:>10} {:>12} {:>12} {:>12} {:>12} {:>14}",
+        "digits", "split_ms", "isqrt_ms", "div_ms", "total_ms", "digits/s"
+    );
+
+    bench_strategy("full recompute", budget, compute_pi_digits_timed);
+
+    let mut engine = PiEngine::new();
+    bench_strategy("resumable engine", budget, |target| engine.extend_to_timed(target));
+}
+
 // ═══════════════════════════════════════════════════════════════
 //  Main
 // ═══════════════════════════════════════════════════════════════
@@ -437,26 +947,114 @@ mod tests {
 
     #[test]
     fn verify_pi_first_50() {
-        let digits = compute_pi_digits(50);
+        let (digits, _) = compute_pi_digits_timed(50);
         let s: String = digits.iter().map(|d| (b'0' + d) as char).collect();
         // Pi = 3.14159265358979323846264338327950288419716939937510
         assert_eq!(s, "14159265358979323846264338327950288419716939937510");
     }
+
+    #[test]
+    fn pi_engine_matches_full_recompute() {
+        // Grow one engine through several targets, the same incremental
+        // access pattern `run()` uses, and check each step's output against
+        // a fresh from-scratch computation. Catches a sign/operand-order
+        // slip in the binary-split merge that would otherwise silently
+        // produce wrong-but-plausible digits.
+        let mut engine = PiEngine::new();
+        for &target in &[50usize, 500, 5000] {
+            let (expected, _) = compute_pi_digits_timed(target);
+            let actual = engine.extend_to(target);
+            assert_eq!(actual, expected, "PiEngine diverged at {} digits", target);
+        }
+    }
+
+    #[test]
+    fn block_frequency_tallies_overlapping_kgrams() {
+        let mut stats = Stats::new();
+        for d in [1u8, 2, 3, 4, 5] {
+            stats.add_digit(d);
+        }
+        // 5 digits -> 4 overlapping 2-grams (12,23,34,45), 3 overlapping
+        // 3-grams (123,234,345).
+        assert_eq!(stats.k2_total, 4);
+        assert_eq!(stats.k3_total, 3);
+        for block in [12, 23, 34, 45] {
+            assert_eq!(stats.k2_counts[block], 1, "missing 2-gram {block}");
+        }
+        for block in [123, 234, 345] {
+            assert_eq!(stats.k3_counts[block], 1, "missing 3-gram {block}");
+        }
+        assert_eq!(
+            stats.chi_squared_k2(),
+            chi_squared_blocks(&stats.k2_counts, stats.k2_total)
+        );
+    }
+
+    #[test]
+    fn local_window_bounds_at_window_size() {
+        let mut stats = Stats::new();
+        for _ in 0..(WINDOW_SIZE + 5) {
+            stats.add_digit(7);
+        }
+        assert_eq!(stats.window_digits.len(), WINDOW_SIZE);
+        assert_eq!(stats.window_counts[7], WINDOW_SIZE as u64);
+        assert_eq!(stats.window_counts.iter().sum::<u64>(), WINDOW_SIZE as u64);
+    }
+
+    #[test]
+    fn export_json_has_expected_shape() {
+        let mut stats = Stats::new();
+        for d in [1u8, 2, 3, 4, 5] {
+            stats.add_digit(d);
+        }
+        let json = stats.export_json(Duration::from_secs(2));
+        assert!(json.trim_start().starts_with('{'));
+        assert!(json.trim_end().ends_with('}'));
+        assert!(json.contains("\"total_digits\": 5"));
+        assert!(json.contains("\"digit_counts\": [0, 1, 1, 1, 1, 1, 0, 0, 0, 0]"));
+        assert!(json.contains("\"max_dev_history\""));
+        assert!(json.contains("\"chi_sq_history\""));
+    }
+
+    #[test]
+    fn export_csv_has_one_row_per_sample() {
+        let mut stats = Stats::new();
+        for d in [1u8, 2, 3, 4, 5] {
+            stats.add_digit(d);
+        }
+        let csv = stats.export_csv(Duration::from_secs(2));
+        assert!(csv.starts_with("# total_digits,5\n"));
+        assert!(csv.contains("sample,max_deviation,entropy,chi_squared\n"));
+        let header_idx = csv.find("sample,max_deviation,entropy,chi_squared\n").unwrap();
+        let data_rows = csv[header_idx..].lines().count() - 1;
+        assert_eq!(data_rows, stats.max_dev_history.len());
+    }
 }
 
 fn main() -> io::Result<()> {
+    let args: Vec<String> = std::env::args().collect();
+    if args.iter().any(|a| a == "--bench") {
+        run_bench();
+        return Ok(());
+    }
+    let export_path = args
+        .iter()
+        .position(|a| a == "--export")
+        .and_then(|i| args.get(i + 1))
+        .cloned();
+
     let mut stdout = io::stdout();
     terminal::enable_raw_mode()?;
     execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
 
-    let result = run(&mut stdout);
+    let result = run(&mut stdout, export_path);
 
     execute!(stdout, cursor::Show, terminal::LeaveAlternateScreen)?;
     terminal::disable_raw_mode()?;
     result
 }
 
-fn run(stdout: &mut io::Stdout) -> io::Result<()> {
+fn run(stdout: &mut io::Stdout, export_path: Option<String>) -> io::Result<()> {
     let running = Arc::new(AtomicBool::new(true));
 
     // Keyboard handler
@@ -486,10 +1084,11 @@ fn run(stdout: &mut io::Stdout) -> io::Result<()> {
     {
         let r = running.clone();
         thread::spawn(move || {
+            let mut engine = PiEngine::new();
             let mut computed = 0usize;
             let mut target = 1_000usize;
             while r.load(Ordering::Relaxed) {
-                let all = compute_pi_digits(target);
+                let all = engine.extend_to(target);
                 let new_digits = all[computed..].to_vec();
                 if tx.send(new_digits).is_err() {
                     break;
@@ -501,7 +1100,7 @@ fn run(stdout: &mut io::Stdout) -> io::Result<()> {
     }
 
     let mut stats = Stats::new();
-    let mut first_draw = true;
+    let mut draw_state = DrawState::new();
     let mut last_draw = Instant::now();
     let mut digit_buf: VecDeque<u8> = VecDeque::new();
 
@@ -518,7 +1117,7 @@ fn run(stdout: &mut io::Stdout) -> io::Result<()> {
 
         // Throttled draw (50ms = ~20fps)
         if last_draw.elapsed() >= Duration::from_millis(50) {
-            draw(stdout, &stats, &mut first_draw)?;
+            draw(stdout, &stats, &mut draw_state)?;
             last_draw = Instant::now();
         }
 
@@ -526,7 +1125,12 @@ fn run(stdout: &mut io::Stdout) -> io::Result<()> {
     }
 
     // Final draw
-    draw(stdout, &stats, &mut first_draw)?;
+    draw(stdout, &stats, &mut draw_state)?;
+
+    if let Some(path) = &export_path {
+        export_stats(path, &stats, stats.start.elapsed())?;
+    }
+
     thread::sleep(Duration::from_secs(1));
     Ok(())
 }